@@ -387,19 +387,23 @@ public struct PendingToolUse: Equatable, Sendable {
   public let timestamp: Date
   public let input: String?
   public let codeChangeInput: CodeChangeInput?
+  /// Destructive patterns found in a pending Bash command, if any.
+  public let dangerAssessment: BashCommandDangerAssessment?
 
   public init(
     toolName: String,
     toolUseId: String,
     timestamp: Date,
     input: String? = nil,
-    codeChangeInput: CodeChangeInput? = nil
+    codeChangeInput: CodeChangeInput? = nil,
+    dangerAssessment: BashCommandDangerAssessment? = nil
   ) {
     self.toolName = toolName
     self.toolUseId = toolUseId
     self.timestamp = timestamp
     self.input = input
     self.codeChangeInput = codeChangeInput
+    self.dangerAssessment = dangerAssessment
   }
 
   /// How long this tool has been pending
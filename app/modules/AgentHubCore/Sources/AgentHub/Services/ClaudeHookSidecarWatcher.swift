@@ -231,7 +231,11 @@ public actor ClaudeHookSidecarWatcher: ClaudeHookSidecarWatcherProtocol {
           toolUseId: decoded.toolUseId ?? "hook-\(sessionId)",
           timestamp: decoded.parsedTimestamp ?? Date(),
           input: decoded.inputPreview,
-          codeChangeInput: decoded.codeChangeInput
+          codeChangeInput: decoded.codeChangeInput,
+          dangerAssessment: BashCommandDangerAnalyzer.assess(
+            toolName: decoded.toolName ?? "",
+            command: decoded.input?.command
+          )
         )
         currentInfo[sessionId] = info
         changed = true
@@ -293,9 +297,13 @@ public actor ClaudeHookSidecarWatcher: ClaudeHookSidecarWatcherProtocol {
     do {
       try handle.seek(toOffset: position)
       let data = handle.readDataToEndOfFile()
-      position = size
-      guard let content = String(data: data, encoding: .utf8) else { return [] }
-      return content.split(separator: "\n", omittingEmptySubsequences: true).map(String.init)
+      let chunk = UTF8ChunkDecoder.decodeChunk(data)
+      position += UInt64(chunk.consumedByteCount)
+
+      if chunk.containedInvalidUTF8 {
+        AppLogger.watcher.warning("[SidecarWatcher] Replaced invalid UTF-8 while reading \(url.path)")
+      }
+      return chunk.lines
     } catch {
       return nil
     }
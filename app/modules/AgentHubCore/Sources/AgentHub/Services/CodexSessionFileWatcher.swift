@@ -45,8 +45,13 @@ public actor CodexSessionFileWatcher {
       return
     }
 
-    var parseResult = CodexSessionJSONLParser.parseSessionFile(
-      at: filePath,
+    // Only complete lines are consumed, so a line Codex is still writing is
+    // read whole by the first incremental read.
+    var filePosition: UInt64 = 0
+    var parseResult = CodexSessionJSONLParser.ParseResult()
+    CodexSessionJSONLParser.parseNewLines(
+      readNewLines(from: filePath, startingAt: &filePosition),
+      into: &parseResult,
       approvalTimeoutSeconds: approvalTimeoutSeconds
     )
     let initialState = buildMonitorState(from: parseResult)
@@ -64,10 +69,11 @@ public actor CodexSessionFileWatcher {
       queue: DispatchQueue.global(qos: .utility)
     )
 
-    var filePosition = getFileSize(filePath)
     let timeout = approvalTimeoutSeconds
     var lastFileEventTime = Date()
     var lastKnownFileSize = filePosition
+    // File size at which stale recovery last found only an unfinished line.
+    var unterminatedTailSize: UInt64 = 0
     var lastEmittedStatus: SessionStatus = parseResult.currentStatus
     let statusTimer = DispatchSource.makeTimerSource(queue: DispatchQueue.global(qos: .utility))
 
@@ -128,24 +134,30 @@ public actor CodexSessionFileWatcher {
     statusTimer.setEventHandler { [weak self] in
       guard let self else { return }
       self.processingQueue.async {
-        let timeSinceLastEvent = Date().timeIntervalSince(lastFileEventTime)
         let currentFileSize = self.getFileSize(filePath)
 
         // Tracks whether this tick changed anything worth persisting back to
         // the actor; no-op ticks skip the updateStoredWatcherInfo Task hop.
         var storedInfoNeedsUpdate = false
 
-        if timeSinceLastEvent > 5 && currentFileSize > lastKnownFileSize {
+        if SessionWatcherTimerPolicy.shouldRecoverStaleWatcher(
+          lastFileEventTime: lastFileEventTime,
+          currentFileSize: currentFileSize,
+          lastKnownFileSize: lastKnownFileSize,
+          unterminatedTailSize: unterminatedTailSize
+        ) {
           var tempPosition = lastKnownFileSize
           let newLines = self.readNewLines(from: filePath, startingAt: &tempPosition)
           if !newLines.isEmpty {
             CodexSessionJSONLParser.parseNewLines(newLines, into: &parseResult, approvalTimeoutSeconds: timeout)
             lastKnownFileSize = tempPosition
             lastFileEventTime = Date()
+            storedInfoNeedsUpdate = true
           } else {
-            lastKnownFileSize = currentFileSize
+            // Only an unfinished line is pending. Keep the position at its
+            // start and don't retry until the file grows.
+            unterminatedTailSize = currentFileSize
           }
-          storedInfoNeedsUpdate = true
         }
 
         let previousStatus = lastEmittedStatus
@@ -260,10 +272,13 @@ public actor CodexSessionFileWatcher {
     do {
       try handle.seek(toOffset: position)
       let data = handle.readDataToEndOfFile()
-      position = currentSize
+      let chunk = UTF8ChunkDecoder.decodeChunk(data)
+      position += UInt64(chunk.consumedByteCount)
 
-      guard let content = String(data: data, encoding: .utf8) else { return [] }
-      return content.components(separatedBy: .newlines).filter { !$0.isEmpty }
+      if chunk.containedInvalidUTF8 {
+        AppLogger.watcher.warning("Replaced invalid UTF-8 while reading \(path)")
+      }
+      return chunk.lines
     } catch {
       return []
     }
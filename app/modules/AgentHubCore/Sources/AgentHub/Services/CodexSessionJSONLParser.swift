@@ -85,11 +85,12 @@ public struct CodexSessionJSONLParser {
     }
     defer { try? handle.close() }
 
-    guard let data = try? handle.readToEnd(),
-          let content = String(data: data, encoding: .utf8) else {
+    guard let data = try? handle.readToEnd() else {
       return result
     }
 
+    let content = UTF8ChunkDecoder.decode(data)
+
     for line in content.split(separator: "\n", omittingEmptySubsequences: true) {
       guard let lineData = line.data(using: .utf8),
             let entry = try? JSONSerialization.jsonObject(with: lineData) as? [String: Any],
@@ -130,11 +131,12 @@ public struct CodexSessionJSONLParser {
   public static func parseSessionSummaryFile(at path: String) -> SessionSummaryParseResult {
     var result = SessionSummaryParseResult()
 
-    guard let data = FileManager.default.contents(atPath: path),
-          let content = String(data: data, encoding: .utf8) else {
+    guard let data = FileManager.default.contents(atPath: path) else {
       return result
     }
 
+    let content = UTF8ChunkDecoder.decode(data)
+
     for line in content.split(separator: "\n", omittingEmptySubsequences: true) {
       guard let lineData = line.data(using: .utf8),
             let entry = try? JSONSerialization.jsonObject(with: lineData) as? [String: Any] else {
@@ -149,11 +151,12 @@ public struct CodexSessionJSONLParser {
   public static func parseSessionFile(at path: String, approvalTimeoutSeconds: Int = 0) -> ParseResult {
     var result = ParseResult()
 
-    guard let data = FileManager.default.contents(atPath: path),
-          let content = String(data: data, encoding: .utf8) else {
+    guard let data = FileManager.default.contents(atPath: path) else {
       return result
     }
 
+    let content = UTF8ChunkDecoder.decode(data)
+
     for line in content.components(separatedBy: .newlines) where !line.isEmpty {
       if let entry = parseEntry(line) {
         processEntry(entry, into: &result)
@@ -444,7 +447,7 @@ public struct CodexSessionJSONLParser {
     let entry = ActivityEntry(
       timestamp: timestamp ?? Date(),
       type: type,
-      description: description,
+      description: TerminalEscapeSanitizer.strip(description),
       toolInput: nil
     )
     result.recentActivities.append(entry)
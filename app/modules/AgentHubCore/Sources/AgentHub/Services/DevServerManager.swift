@@ -538,7 +538,10 @@ public final class DevServerManager {
 
     let handler: @Sendable (FileHandle) -> Void = { [weak self] handle in
       let data = handle.availableData
-      guard !data.isEmpty, let text = String(data: data, encoding: .utf8) else { return }
+      guard !data.isEmpty else { return }
+      // Lossy: a pipe read can split a multi-byte glyph (Vite's "➜") and
+      // must not drop the chunk carrying the readiness line.
+      let text = UTF8ChunkDecoder.decode(data)
       Task { @MainActor [weak self] in
         self?.handleServerOutput(text, key: key, port: port, patterns: patterns)
       }
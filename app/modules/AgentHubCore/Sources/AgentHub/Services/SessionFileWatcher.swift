@@ -93,8 +93,15 @@ public actor SessionFileWatcher {
       return
     }
 
-    // Initial parse
-    var parseResult = SessionJSONLParser.parseSessionFile(at: filePath, approvalTimeoutSeconds: approvalTimeoutSeconds)
+    // Initial parse. Only complete lines are consumed, so a line the CLI is
+    // still writing is read whole by the first incremental read.
+    var filePosition: UInt64 = 0
+    var parseResult = SessionJSONLParser.ParseResult()
+    SessionJSONLParser.parseNewLines(
+      readNewLines(from: filePath, startingAt: &filePosition),
+      into: &parseResult,
+      approvalTimeoutSeconds: approvalTimeoutSeconds
+    )
 
     // Seed hook-sourced pending info (if the hook fired before we started).
     var hookPendingInfo: SessionJSONLParser.PendingToolInfo?
@@ -127,15 +134,14 @@ public actor SessionFileWatcher {
       queue: DispatchQueue.global(qos: .utility)
     )
 
-    // Track file position for incremental reading
-    var filePosition = getFileSize(filePath)
-
     // Capture timeout for use in closures
     let timeout = approvalTimeoutSeconds
 
     // Shared state between closures - protected by processingQueue
     var lastFileEventTime = Date()
     var lastKnownFileSize = filePosition
+    // File size at which stale recovery last found only an unfinished line.
+    var unterminatedTailSize: UInt64 = 0
     var lastEmittedStatus: SessionStatus = initialState.status
 
     let statusTimer = DispatchSource.makeTimerSource(queue: DispatchQueue.global(qos: .utility))
@@ -215,7 +221,6 @@ public actor SessionFileWatcher {
 
       self.processingQueue.async {
         // Health check: detect stale file watcher
-        let timeSinceLastEvent = Date().timeIntervalSince(lastFileEventTime)
         let currentFileSize = self.getFileSize(filePath)
 
         // Tracks whether this tick changed anything worth persisting back to
@@ -223,7 +228,12 @@ public actor SessionFileWatcher {
         var storedInfoNeedsUpdate = false
 
         // If file has grown but no events in 5+ seconds, watcher may be stale
-        if timeSinceLastEvent > 5 && currentFileSize > lastKnownFileSize {
+        if SessionWatcherTimerPolicy.shouldRecoverStaleWatcher(
+          lastFileEventTime: lastFileEventTime,
+          currentFileSize: currentFileSize,
+          lastKnownFileSize: lastKnownFileSize,
+          unterminatedTailSize: unterminatedTailSize
+        ) {
           AppLogger.watcher.warning("Stale watcher detected for \(sessionId), recovering...")
 
           // Recovery: re-read new content manually
@@ -236,11 +246,13 @@ public actor SessionFileWatcher {
             // Update tracking
             lastKnownFileSize = tempPosition
             lastFileEventTime = Date()
+            storedInfoNeedsUpdate = true
           } else {
-            // No new lines but file size changed - update tracking anyway
-            lastKnownFileSize = currentFileSize
+            // Only an unfinished line is pending (e.g. the CLI was killed
+            // mid-write). Keep the position at its start so it's read whole
+            // once the newline lands, and don't retry until the file grows.
+            unterminatedTailSize = currentFileSize
           }
-          storedInfoNeedsUpdate = true
         }
 
         // Re-evaluate status based on current time
@@ -489,7 +501,9 @@ public actor SessionFileWatcher {
     return UInt64(st.st_size)
   }
 
-  private nonisolated func readNewLines(from path: String, startingAt position: inout UInt64) -> [String] {
+  /// Reads the complete lines appended since `position` and advances it past
+  /// them. Internal so tests can drive it against a file written in parts.
+  nonisolated func readNewLines(from path: String, startingAt position: inout UInt64) -> [String] {
     guard let handle = FileHandle(forReadingAtPath: path) else { return [] }
     defer { try? handle.close() }
 
@@ -499,10 +513,13 @@ public actor SessionFileWatcher {
     do {
       try handle.seek(toOffset: position)
       let data = handle.readDataToEndOfFile()
-      position = currentSize
+      let chunk = UTF8ChunkDecoder.decodeChunk(data)
+      position += UInt64(chunk.consumedByteCount)
 
-      guard let content = String(data: data, encoding: .utf8) else { return [] }
-      return content.components(separatedBy: .newlines).filter { !$0.isEmpty }
+      if chunk.containedInvalidUTF8 {
+        AppLogger.watcher.warning("Replaced invalid UTF-8 while reading \(path)")
+      }
+      return chunk.lines
     } catch {
       return []
     }
@@ -542,7 +559,8 @@ public actor SessionFileWatcher {
         toolUseId: p.toolUseId,
         timestamp: p.timestamp,
         input: p.input,
-        codeChangeInput: p.codeChangeInput
+        codeChangeInput: p.codeChangeInput,
+        dangerAssessment: p.dangerAssessment
       )
     } else {
       pendingToolUse = nil
@@ -25,6 +25,7 @@ public struct SessionJSONLParser {
   /// Raw entry from session JSONL file
   public struct SessionEntry: Decodable {
     let type: String
+    let subtype: String?
     let timestamp: String?
     let uuid: String?
     let message: MessageContent?
@@ -111,6 +112,24 @@ public struct SessionJSONLParser {
     public let timestamp: Date
     public let input: String?
     public let codeChangeInput: CodeChangeInput?
+    /// Destructive patterns found in a pending Bash command, if any.
+    public let dangerAssessment: BashCommandDangerAssessment?
+
+    public init(
+      toolName: String,
+      toolUseId: String,
+      timestamp: Date,
+      input: String?,
+      codeChangeInput: CodeChangeInput?,
+      dangerAssessment: BashCommandDangerAssessment? = nil
+    ) {
+      self.toolName = toolName
+      self.toolUseId = toolUseId
+      self.timestamp = timestamp
+      self.input = input
+      self.codeChangeInput = codeChangeInput
+      self.dangerAssessment = dangerAssessment
+    }
   }
 
   // MARK: - Public API
@@ -122,11 +141,11 @@ public struct SessionJSONLParser {
   public static func parseSessionFile(at path: String, approvalTimeoutSeconds: Int = 0) -> ParseResult {
     var result = ParseResult()
 
-    guard let data = FileManager.default.contents(atPath: path),
-          let content = String(data: data, encoding: .utf8) else {
+    guard let data = FileManager.default.contents(atPath: path) else {
       return result
     }
 
+    let content = UTF8ChunkDecoder.decode(data)
     let lines = content.components(separatedBy: .newlines)
 
     // One decoder per batch: JSONDecoder allocation per line is a measurable hot spot,
@@ -245,6 +264,14 @@ public struct SessionJSONLParser {
       // Summary entries may contain git branch info
       break
 
+    case "system":
+      // After /compact (manual or auto) the old conversation is no longer in
+      // context. Drop the stale occupancy until the next assistant usage
+      // reports the post-compaction size.
+      if entry.subtype == "compact_boundary" {
+        result.lastInputTokens = 0
+      }
+
     default:
       break
     }
@@ -277,7 +304,11 @@ public struct SessionJSONLParser {
             toolUseId: id,
             timestamp: timestamp ?? Date(),
             input: inputPreview,
-            codeChangeInput: codeChangeInput
+            codeChangeInput: codeChangeInput,
+            dangerAssessment: BashCommandDangerAnalyzer.assess(
+              toolName: name,
+              command: (block.input?.value as? [String: Any])?["command"] as? String
+            )
           )
 
           addActivity(
@@ -455,7 +486,7 @@ public struct SessionJSONLParser {
     let entry = ActivityEntry(
       timestamp: timestamp ?? Date(),
       type: type,
-      description: description,
+      description: TerminalEscapeSanitizer.strip(description),
       toolInput: codeChangeInput
     )
 
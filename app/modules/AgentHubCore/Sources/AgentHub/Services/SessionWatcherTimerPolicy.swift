@@ -46,6 +46,22 @@ struct SessionWatcherTimerPolicy {
     }
   }
 
+  /// Whether a health tick should re-read the file because events seem to
+  /// have stopped while it kept growing. `unterminatedTailSize` is the size at
+  /// which the last recovery found only an unfinished line; until the file
+  /// grows past it, re-reading would find the same line again.
+  static func shouldRecoverStaleWatcher(
+    lastFileEventTime: Date,
+    currentFileSize: UInt64,
+    lastKnownFileSize: UInt64,
+    unterminatedTailSize: UInt64,
+    now: Date = Date()
+  ) -> Bool {
+    now.timeIntervalSince(lastFileEventTime) > staleWatcherRecoveryInterval
+      && currentFileSize > lastKnownFileSize
+      && currentFileSize > unterminatedTailSize
+  }
+
   static func needsHealthCheck(for status: SessionStatus) -> Bool {
     switch status {
     case .idle, .waitingForUser:
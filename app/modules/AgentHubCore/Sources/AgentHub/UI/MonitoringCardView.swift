@@ -683,6 +683,19 @@ public struct MonitoringCardView: View {
 
       // Action buttons — fixed size, never shrink
       HStack(spacing: 6) {
+        // Destructive Bash command awaiting approval
+        if let dangerAssessment = state?.pendingToolUse?.dangerAssessment {
+          HStack(spacing: 4) {
+            Image(systemName: "exclamationmark.triangle.fill")
+              .font(.caption2)
+            Text("Risky")
+              .font(.secondaryCaption)
+          }
+          .foregroundColor(.red)
+          .fixedSize()
+          .help(dangerAssessment.summary)
+        }
+
         // Pending changes preview button - show immediately when code change tool is detected
         if let pendingToolUse = state?.pendingToolUse,
            pendingToolUse.isCodeChangeTool {
@@ -0,0 +1,109 @@
+//
+//  BashCommandDangerAnalyzer.swift
+//  AgentHub
+//
+//  Flags destructive shell patterns in pending Bash tool calls so the
+//  monitoring card can call them out before the user approves them.
+//
+
+import Foundation
+
+// MARK: - BashCommandDangerAssessment
+
+/// Destructive patterns found in a single Bash tool command.
+public struct BashCommandDangerAssessment: Equatable, Sendable {
+
+  public enum Reason: String, CaseIterable, Equatable, Sendable {
+    case recursiveDeleteOfRoot
+    case forcePush
+    case discardsLocalChanges
+    case pipesDownloadToShell
+    case forkBomb
+    case overwritesDisk
+
+    /// Short human-readable description shown in tooltips.
+    public var displayText: String {
+      switch self {
+      case .recursiveDeleteOfRoot: return "Recursively deletes /, ~ or a bare wildcard"
+      case .forcePush: return "Force-pushes and may overwrite remote history"
+      case .discardsLocalChanges: return "Discards uncommitted changes"
+      case .pipesDownloadToShell: return "Pipes a download straight into a shell"
+      case .forkBomb: return "Fork bomb"
+      case .overwritesDisk: return "Writes directly to a disk device or formats it"
+      }
+    }
+  }
+
+  public let reasons: [Reason]
+
+  public init(reasons: [Reason]) {
+    self.reasons = reasons
+  }
+
+  /// One line per reason, suitable for `.help(_:)`.
+  public var summary: String {
+    reasons.map(\.displayText).joined(separator: "\n")
+  }
+}
+
+// MARK: - BashCommandDangerAnalyzer
+
+/// Heuristic, pattern-based analyzer. It errs on the side of flagging: a false
+/// positive costs the user a second look, a false negative costs their disk.
+enum BashCommandDangerAnalyzer {
+
+  /// Compiled once — the patterns are constant and NSRegularExpression is
+  /// documented thread-safe, so the parser can share them across watcher queues.
+  /// `/` and `$HOME` may be quoted; a quoted `~` or single-quoted `$HOME` is
+  /// not expanded by the shell and names a literal directory. git may be given
+  /// `-C <dir>` / `-c <key=value>` before the subcommand, since that is how
+  /// agents usually write them.
+  private static let rules: [(BashCommandDangerAssessment.Reason, NSRegularExpression)] = {
+    let patterns: [(BashCommandDangerAssessment.Reason, String)] = [
+      (
+        .recursiveDeleteOfRoot,
+        #"\brm\s+(?:-\S+\s+)*(?:-[a-zA-Z]*[rR][a-zA-Z]*|--recursive)\s+(?:-\S+\s+)*(?:["']?/["']?\*?|~/?\*?|"?\$\{?HOME\}?/?"?\*?|\*)(?=\s|$|[;&|])"#
+      ),
+      (
+        .forcePush,
+        #"\bgit(?:\s+-[Cc]\s+\S+)*\s+push\b[^;&|\n]*\s(?:--force(?![\w-])|-[a-zA-Z]*f[a-zA-Z]*\b|\+[^\s]+)"#
+      ),
+      (
+        .discardsLocalChanges,
+        #"\bgit(?:\s+-[Cc]\s+\S+)*\s+(?:reset\s+(?:\S+\s+)*--hard\b|clean\s+(?:\S+\s+)*(?:-[a-zA-Z]*f|--force(?![\w-])))"#
+      ),
+      (
+        .pipesDownloadToShell,
+        #"\b(?:curl|wget)\b[^;&|\n]*\|\s*(?:sudo\s+)?(?:ba|z|k|da|fi)?sh\b"#
+      ),
+      (
+        .forkBomb,
+        #":\s*\(\s*\)\s*\{\s*:\s*\|\s*:\s*&\s*\}\s*;\s*:"#
+      ),
+      (
+        .overwritesDisk,
+        #"\bdd\b[^;&|\n]*\bof=/dev/(?:r?disk|sd|nvme|hd)|\bmkfs(?:\.\w+)?\b|>\s*/dev/(?:r?disk|sd|nvme|hd)"#
+      ),
+    ]
+
+    return patterns.compactMap { reason, pattern in
+      guard let regex = try? NSRegularExpression(pattern: pattern) else { return nil }
+      return (reason, regex)
+    }
+  }()
+
+  /// Returns `nil` when no destructive pattern is found.
+  static func assess(_ command: String) -> BashCommandDangerAssessment? {
+    let range = NSRange(command.startIndex..., in: command)
+    let reasons = rules.compactMap { reason, regex in
+      regex.firstMatch(in: command, range: range) == nil ? nil : reason
+    }
+    return reasons.isEmpty ? nil : BashCommandDangerAssessment(reasons: reasons)
+  }
+
+  /// Assesses a tool call only when it is a Bash invocation with a command.
+  static func assess(toolName: String, command: String?) -> BashCommandDangerAssessment? {
+    guard toolName == "Bash", let command, !command.isEmpty else { return nil }
+    return assess(command)
+  }
+}
@@ -14,6 +14,9 @@ enum LocalhostURLNormalizer {
   private static let decorativeWildcardCharacters = CharacterSet(charactersIn: "*.")
 
   static func extractFirstURL(from text: String) -> URL? {
+    // Dev servers color their banners; an escape code between host and port
+    // would otherwise hide the URL entirely.
+    let text = TerminalEscapeSanitizer.strip(text)
     guard let regex = try? NSRegularExpression(pattern: localhostPattern),
           let match = regex.firstMatch(in: text, range: NSRange(text.startIndex..., in: text)),
           let range = Range(match.range, in: text) else {
@@ -24,6 +27,7 @@ enum LocalhostURLNormalizer {
   }
 
   static func extractLastURL(from text: String) -> URL? {
+    let text = TerminalEscapeSanitizer.strip(text)
     guard let regex = try? NSRegularExpression(pattern: localhostPattern) else {
       return nil
     }
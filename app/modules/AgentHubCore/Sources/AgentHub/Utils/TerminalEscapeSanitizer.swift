@@ -0,0 +1,70 @@
+//
+//  TerminalEscapeSanitizer.swift
+//  AgentHub
+//
+//  Strips ANSI escape sequences and stray control characters from text the
+//  agents copied out of a terminal (Bash output, dev-server banners) before
+//  AgentHub renders it or scans it for URLs.
+//
+
+import Foundation
+
+enum TerminalEscapeSanitizer {
+
+  /// CSI (`ESC [ … final`); OSC (`ESC ] …`) and DCS/SOS/PM/APC (`ESC P`,
+  /// `X`, `^`, `_`), each ended by BEL or `ESC \`; charset designators such
+  /// as `ESC ( B` from `tput sgr0`; `ESC 7`/`ESC 8` and the other private
+  /// two-character escapes; and the remaining Fe escapes. A sequence cut off
+  /// by a preview `prefix(_:)` is matched up to end of string so no `[3`
+  /// fragment is left behind; an unterminated string sequence stops at the
+  /// end of its line so it can't swallow a multi-line dev-server banner. Compiled once — NSRegularExpression is
+  /// documented thread-safe.
+  private static let escapeSequenceRegex = try? NSRegularExpression(
+    pattern: #"\u001B(?:\[[0-?]*[ -/]*(?:[@-~]|$)|[\]PX^_][^\u0007\u001B\n]*(?:\u0007|\u001B\\|(?=\n)|$)|[()*+][0-9A-Za-z]?|[0-9=<>]|[@-Z\\-_])"#
+  )
+
+  /// C0 controls other than tab, newline and carriage return, plus DEL and
+  /// the C1 range. Carriage returns are resolved separately.
+  private static let controlCharacterRegex = try? NSRegularExpression(
+    pattern: #"[\u0000-\u0008\u000B\u000C\u000E-\u001F\u007F-\u009F]"#
+  )
+
+  static func strip(_ text: String) -> String {
+    // Fast path: almost every line is plain text.
+    guard text.unicodeScalars.contains(where: isControl) else { return text }
+
+    var sanitized = text
+    for regex in [escapeSequenceRegex, controlCharacterRegex].compactMap({ $0 }) {
+      sanitized = regex.stringByReplacingMatches(
+        in: sanitized,
+        range: NSRange(sanitized.startIndex..., in: sanitized),
+        withTemplate: ""
+      )
+    }
+    if sanitized.unicodeScalars.contains("\r") {
+      sanitized = collapseCarriageReturns(sanitized)
+    }
+    return sanitized
+  }
+
+  /// A terminal returns to the start of the line on `\r` and draws over it,
+  /// so of `10%\r20%\r30%` only the last frame is ever visible. Keep the last
+  /// non-empty segment of each line; `\r\n` is an ordinary line break.
+  private static func collapseCarriageReturns(_ text: String) -> String {
+    text
+      .replacingOccurrences(of: "\r\n", with: "\n")
+      .components(separatedBy: "\n")
+      .map { line in
+        line.components(separatedBy: "\r").last(where: { !$0.isEmpty }) ?? ""
+      }
+      .joined(separator: "\n")
+  }
+
+  private static func isControl(_ scalar: Unicode.Scalar) -> Bool {
+    switch scalar.value {
+    case 0x09, 0x0A: return false
+    case 0x00...0x1F, 0x7F...0x9F: return true
+    default: return false
+    }
+  }
+}
@@ -0,0 +1,60 @@
+//
+//  UTF8ChunkDecoder.swift
+//  AgentHub
+//
+//  Lossy UTF-8 decoding for session files and child-process output read in
+//  chunks. `String(data:encoding:)` returns nil for the whole chunk when a
+//  single byte is invalid, which silently dropped every line in it.
+//
+
+import Foundation
+
+enum UTF8ChunkDecoder {
+
+  struct DecodedChunk: Equatable {
+    /// Complete, non-empty lines, with invalid bytes replaced by U+FFFD.
+    let lines: [String]
+    /// Bytes up to and including the last `\n`. A line the writer hasn't
+    /// finished is not counted, so the next read starts at its first byte.
+    let consumedByteCount: Int
+    /// Whether any byte had to be replaced.
+    let containedInvalidUTF8: Bool
+  }
+
+  /// Splits a chunk read from a file that may still be appended to into its
+  /// complete lines.
+  ///
+  /// The writer can be caught mid-line, so everything after the last `\n` is
+  /// held back; advancing the read offset by `consumedByteCount` picks the
+  /// line up whole on the next read. A `\n` byte never occurs inside a
+  /// multi-byte UTF-8 sequence, so this also keeps split characters intact.
+  static func decodeChunk(_ data: Data) -> DecodedChunk {
+    guard let lastNewline = data.lastIndex(of: 0x0A) else {
+      return DecodedChunk(lines: [], consumedByteCount: 0, containedInvalidUTF8: false)
+    }
+
+    let complete = data[data.startIndex...lastNewline]
+    var lines: [String] = []
+    var containedInvalidUTF8 = false
+
+    for lineData in complete.split(separator: 0x0A) {
+      if let line = String(data: lineData, encoding: .utf8) {
+        lines.append(line)
+      } else {
+        lines.append(String(decoding: lineData, as: UTF8.self))
+        containedInvalidUTF8 = true
+      }
+    }
+
+    return DecodedChunk(
+      lines: lines,
+      consumedByteCount: complete.count,
+      containedInvalidUTF8: containedInvalidUTF8
+    )
+  }
+
+  /// Decodes a complete buffer, replacing invalid bytes with U+FFFD.
+  static func decode(_ data: Data) -> String {
+    String(decoding: data, as: UTF8.self)
+  }
+}
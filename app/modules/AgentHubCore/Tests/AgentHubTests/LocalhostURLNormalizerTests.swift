@@ -15,6 +15,23 @@ struct LocalhostURLNormalizerTests {
     #expect(url?.absoluteString == "http://localhost:3000")
   }
 
+  @Test("Extracts localhost URL from colored dev server output")
+  func extractsURLFromColoredServerOutput() {
+    let text = "  ➜  Local:   \u{1B}[36mhttp://localhost:\u{1B}[1m5173\u{1B}[22m/\u{1B}[39m"
+
+    let url = LocalhostURLNormalizer.extractFirstURL(from: text)
+
+    #expect(url?.absoluteString == "http://localhost:5173")
+  }
+
+  @Test("Finds URL on the line after an unterminated OSC sequence")
+  func findsURLAfterUnterminatedOSC() {
+    let text = "\u{1B}]0;vite dev\n  ➜  Local:   http://localhost:5173/\n"
+
+    #expect(LocalhostURLNormalizer.extractFirstURL(from: text)?.absoluteString == "http://localhost:5173")
+    #expect(LocalhostURLNormalizer.extractLastURL(from: text)?.absoluteString == "http://localhost:5173")
+  }
+
   @Test("Strips decorative wildcard suffix but keeps meaningful route")
   func stripsDecorativeWildcardSuffixButKeepsRoute() {
     let url = LocalhostURLNormalizer.sanitize("http://localhost:3000/dashboard/**")
@@ -162,7 +162,9 @@ struct SessionFileWatcherApprovalModeTests {
     }
 
     func writeSessionFile(lines: [String]) throws {
-      try lines.joined(separator: "\n").write(to: sessionFileURL, atomically: true, encoding: .utf8)
+      // Newline-terminated like the CLI writes it; the watcher leaves an
+      // unterminated last line for the next read.
+      try lines.map { $0 + "\n" }.joined().write(to: sessionFileURL, atomically: true, encoding: .utf8)
     }
 
     func writeSidecarLines(_ objects: [[String: Any]]) throws {
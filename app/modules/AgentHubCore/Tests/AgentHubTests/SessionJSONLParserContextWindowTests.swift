@@ -0,0 +1,60 @@
+import Foundation
+import Testing
+
+@testable import AgentHubCore
+
+@Suite("SessionJSONLParser context window")
+struct SessionJSONLParserContextWindowTests {
+
+  private static let assistantWithUsage = """
+    {"type":"assistant","timestamp":"2026-01-01T00:00:00Z","message":{"role":"assistant","model":"claude-opus-4-1","content":[{"type":"text","text":"Done"}],"usage":{"input_tokens":1200,"cache_read_input_tokens":150000,"cache_creation_input_tokens":800,"output_tokens":300}}}
+    """
+
+  private static let compactBoundary = """
+    {"type":"system","subtype":"compact_boundary","timestamp":"2026-01-01T00:01:00Z","content":"Conversation compacted","compactMetadata":{"trigger":"manual","preTokens":152000}}
+    """
+
+  @Test("Context occupancy counts input plus cache tokens of the last turn")
+  func occupancyCountsInputAndCacheTokens() {
+    var result = SessionJSONLParser.ParseResult()
+    SessionJSONLParser.parseNewLines([Self.assistantWithUsage], into: &result)
+
+    #expect(result.lastInputTokens == 152_000)
+  }
+
+  @Test("Compact boundary clears stale context occupancy")
+  func compactBoundaryClearsOccupancy() {
+    var result = SessionJSONLParser.ParseResult()
+    SessionJSONLParser.parseNewLines([Self.assistantWithUsage, Self.compactBoundary], into: &result)
+
+    #expect(result.lastInputTokens == 0)
+    // Cumulative cost counters are unaffected by compaction.
+    #expect(result.cacheReadTokens == 150_000)
+    #expect(result.totalOutputTokens == 300)
+  }
+
+  @Test("Next assistant usage after compaction reports the new occupancy")
+  func usageAfterCompactionReportsNewOccupancy() {
+    let postCompaction = """
+      {"type":"assistant","timestamp":"2026-01-01T00:02:00Z","message":{"role":"assistant","content":[{"type":"text","text":"Continuing"}],"usage":{"input_tokens":900,"cache_read_input_tokens":0,"cache_creation_input_tokens":18000,"output_tokens":40}}}
+      """
+    var result = SessionJSONLParser.ParseResult()
+    SessionJSONLParser.parseNewLines(
+      [Self.assistantWithUsage, Self.compactBoundary, postCompaction],
+      into: &result
+    )
+
+    #expect(result.lastInputTokens == 18_900)
+  }
+
+  @Test("Other system entries leave occupancy alone")
+  func otherSystemEntriesLeaveOccupancyAlone() {
+    let hookNotice = """
+      {"type":"system","subtype":"informational","timestamp":"2026-01-01T00:01:00Z","content":"Running hook"}
+      """
+    var result = SessionJSONLParser.ParseResult()
+    SessionJSONLParser.parseNewLines([Self.assistantWithUsage, hookNotice], into: &result)
+
+    #expect(result.lastInputTokens == 152_000)
+  }
+}
@@ -0,0 +1,236 @@
+import Foundation
+import Testing
+
+@testable import AgentHubCore
+
+/// Deterministic mutation tests for the Claude and Codex JSONL parsers.
+///
+/// Both parsers read files written by another process while it is still
+/// writing them, so they must tolerate truncated, corrupted and mistyped lines
+/// without trapping and without growing their capped collections. Seeds mirror
+/// real transcript shapes. Mutations come from a fixed-seed generator and
+/// type-swapped lines are built in sorted key order, so every run feeds the
+/// parsers the same lines in the same order.
+@Suite("Session JSONL parser fuzzing")
+struct SessionJSONLParserFuzzTests {
+
+  // MARK: - Seeds
+
+  private static let claudeSeeds: [String] = [
+    #"{"type":"assistant","timestamp":"2026-01-01T00:00:00Z","uuid":"a1","message":{"role":"assistant","model":"claude-opus-4-1","content":[{"type":"tool_use","id":"tu1","name":"Bash","input":{"command":"npm run dev -- --port 5173"}}],"usage":{"input_tokens":1200,"cache_read_input_tokens":150000,"cache_creation_input_tokens":800,"output_tokens":300}}}"#,
+    #"{"type":"user","timestamp":"2026-01-01T00:00:01Z","message":{"role":"user","content":[{"type":"tool_result","tool_use_id":"tu1","content":[{"type":"text","text":"\u001b[32m➜\u001b[39m Local: http://localhost:5173/ see https://vite.dev/guide"}]}]}}"#,
+    #"{"type":"assistant","timestamp":"2026-01-01T00:00:02Z","message":{"role":"assistant","content":[{"type":"tool_use","id":"tu2","name":"Edit","input":{"file_path":"/tmp/project/App.swift","old_string":"let a = 1","new_string":"let a = 2","replace_all":false}}]}}"#,
+    #"{"type":"assistant","timestamp":"2026-01-01T00:00:03Z","message":{"role":"assistant","content":[{"type":"tool_use","id":"tu3","name":"mcp__excalidraw__create_view","input":{"elements":[{"type":"rectangle","x":1,"y":2}]}}]}}"#,
+    #"{"type":"assistant","timestamp":"2026-01-01T00:00:04Z","message":{"role":"assistant","content":[{"type":"thinking","thinking":"…"},{"type":"text","text":"Done. ```mermaid\ngraph TD; A-->B\n```"}]}}"#,
+    #"{"type":"system","subtype":"compact_boundary","timestamp":"2026-01-01T00:00:05Z","compactMetadata":{"trigger":"auto","preTokens":190000}}"#,
+  ]
+
+  private static let codexSeeds: [String] = [
+    #"{"type":"session_meta","timestamp":"2026-01-01T00:00:00.000Z","payload":{"id":"session-1","timestamp":"2026-01-01T00:00:00.000Z","cwd":"/tmp/project"}}"#,
+    #"{"type":"turn_context","timestamp":"2026-01-01T00:00:00.500Z","payload":{"model":"gpt-5-codex","cwd":"/tmp/project"}}"#,
+    #"{"type":"event_msg","timestamp":"2026-01-01T00:00:01.000Z","payload":{"type":"user_message","message":"Start the dev server"}}"#,
+    #"{"type":"response_item","timestamp":"2026-01-01T00:00:02.000Z","payload":{"type":"function_call","name":"shell","call_id":"call-1","arguments":"{\"command\":[\"npm\",\"run\",\"dev\"]}"}}"#,
+    #"{"type":"response_item","timestamp":"2026-01-01T00:00:03.000Z","payload":{"type":"function_call_output","call_id":"call-1","output":"ready on http://localhost:3000 docs https://example.com/docs"}}"#,
+    #"{"type":"event_msg","timestamp":"2026-01-01T00:00:04.000Z","payload":{"type":"token_count","info":{"total_token_usage":{"input_tokens":5000,"cached_input_tokens":2000,"output_tokens":700},"last_token_usage":{"input_tokens":900,"cached_input_tokens":100,"output_tokens":50}}}}"#,
+    #"{"type":"event_msg","timestamp":"2026-01-01T00:00:05.000Z","payload":{"type":"mcp_tool_call_end","invocation":{"server":"excalidraw","tool":"create_view","arguments":{}},"result":{"Ok":{"content":[{"type":"text","text":"ok"}]}}}}"#,
+    #"{"type":"event_msg","timestamp":"2026-01-01T00:00:06.000Z","payload":{"type":"agent_message","message":"Running at http://localhost:3000"}}"#,
+  ]
+
+  // MARK: - Tests
+
+  @Test("Claude parser survives truncated lines")
+  func claudeParserSurvivesTruncation() {
+    var result = SessionJSONLParser.ParseResult()
+    for seed in Self.claudeSeeds {
+      SessionJSONLParser.parseNewLines(Self.truncations(of: seed), into: &result)
+    }
+    Self.expectClaudeBounds(result)
+  }
+
+  @Test("Claude parser survives corrupted bytes")
+  func claudeParserSurvivesCorruption() {
+    var generator = SeededGenerator(seed: 0xA6E7_4B0B)
+    var result = SessionJSONLParser.ParseResult()
+    for seed in Self.claudeSeeds {
+      SessionJSONLParser.parseNewLines(Self.corruptions(of: seed, using: &generator), into: &result)
+    }
+    Self.expectClaudeBounds(result)
+  }
+
+  @Test("Claude parser survives mistyped fields")
+  func claudeParserSurvivesTypeConfusion() {
+    var result = SessionJSONLParser.ParseResult()
+    for seed in Self.claudeSeeds {
+      SessionJSONLParser.parseNewLines(Self.typeConfusions(of: seed), into: &result)
+    }
+    Self.expectClaudeBounds(result)
+  }
+
+  @Test("Codex parser survives truncated lines")
+  func codexParserSurvivesTruncation() {
+    var result = CodexSessionJSONLParser.ParseResult()
+    for seed in Self.codexSeeds {
+      CodexSessionJSONLParser.parseNewLines(Self.truncations(of: seed), into: &result)
+    }
+    Self.expectCodexBounds(result)
+  }
+
+  @Test("Codex parser survives corrupted bytes")
+  func codexParserSurvivesCorruption() {
+    var generator = SeededGenerator(seed: 0xC0DE_C5E5)
+    var result = CodexSessionJSONLParser.ParseResult()
+    for seed in Self.codexSeeds {
+      CodexSessionJSONLParser.parseNewLines(Self.corruptions(of: seed, using: &generator), into: &result)
+    }
+    Self.expectCodexBounds(result)
+  }
+
+  @Test("Codex parser survives mistyped fields")
+  func codexParserSurvivesTypeConfusion() {
+    var result = CodexSessionJSONLParser.ParseResult()
+    for seed in Self.codexSeeds {
+      CodexSessionJSONLParser.parseNewLines(Self.typeConfusions(of: seed), into: &result)
+    }
+    Self.expectCodexBounds(result)
+  }
+
+  @Test("Unmutated seeds still parse")
+  func unmutatedSeedsStillParse() {
+    var claude = SessionJSONLParser.ParseResult()
+    SessionJSONLParser.parseNewLines(Self.claudeSeeds, into: &claude)
+    #expect(claude.model == "claude-opus-4-1")
+    #expect(claude.detectedLocalhostURL?.port == 5173)
+
+    var codex = CodexSessionJSONLParser.ParseResult()
+    CodexSessionJSONLParser.parseNewLines(Self.codexSeeds, into: &codex)
+    #expect(codex.lastInputTokens == 1_000)
+    #expect(codex.detectedLocalhostURL?.port == 3000)
+  }
+
+  // MARK: - Invariants
+
+  private static func expectClaudeBounds(_ result: SessionJSONLParser.ParseResult) {
+    #expect(result.recentActivities.count <= 100)
+    #expect(result.detectedResourceLinks.count <= 50)
+    #expect(result.detectedMCPAppResources.count <= 50)
+    #expect(result.detectedMCPAppInvocations.count <= 12)
+  }
+
+  private static func expectCodexBounds(_ result: CodexSessionJSONLParser.ParseResult) {
+    #expect(result.recentActivities.count <= 100)
+    #expect(result.detectedResourceLinks.count <= 50)
+    #expect(result.detectedMCPAppResources.count <= 50)
+    #expect(result.detectedMCPAppInvocations.count <= 12)
+  }
+
+  // MARK: - Mutations
+
+  /// Every prefix of the line, as seen when a read lands mid-write.
+  private static func truncations(of line: String) -> [String] {
+    let characters = Array(line)
+    return (1..<characters.count).map { String(characters[..<$0]) }
+  }
+
+  /// Lines with a few bytes replaced by JSON-significant or control characters.
+  private static func corruptions(
+    of line: String,
+    using generator: inout SeededGenerator,
+    count: Int = 200
+  ) -> [String] {
+    let replacements: [Character] = ["{", "}", "[", "]", "\"", ":", ",", "\\", "0", "-", "e", "\u{1B}", "\u{0}", "\n", "é", "➜"]
+    let characters = Array(line)
+
+    return (0..<count).map { _ in
+      var mutated = characters
+      for _ in 0...generator.index(below: 4) {
+        let index = generator.index(below: mutated.count)
+        mutated[index] = replacements[generator.index(below: replacements.count)]
+      }
+      return String(mutated)
+    }
+  }
+
+  /// The line re-encoded once per value, with that value swapped for each
+  /// other JSON type.
+  private static func typeConfusions(of line: String) -> [String] {
+    guard let data = line.data(using: .utf8),
+          let root = try? JSONSerialization.jsonObject(with: data) else {
+      return []
+    }
+
+    let substitutes: [Any] = [NSNull(), 0, -1, 1.5, true, "", "x", [Any](), [String: Any]()]
+    var lines: [String] = []
+
+    for path in valuePaths(in: root) {
+      for substitute in substitutes {
+        let mutated = replacing(path, in: root, with: substitute)
+        if let data = try? JSONSerialization.data(withJSONObject: mutated, options: [.sortedKeys]),
+           let text = String(data: data, encoding: .utf8) {
+          lines.append(text)
+        }
+      }
+    }
+    return lines
+  }
+
+  private enum PathComponent {
+    case key(String)
+    case index(Int)
+  }
+
+  private static func valuePaths(in value: Any, prefix: [PathComponent] = []) -> [[PathComponent]] {
+    var paths: [[PathComponent]] = prefix.isEmpty ? [] : [prefix]
+    if let dictionary = value as? [String: Any] {
+      for key in dictionary.keys.sorted() {
+        paths += valuePaths(in: dictionary[key]!, prefix: prefix + [.key(key)])
+      }
+    } else if let array = value as? [Any] {
+      for (index, child) in array.enumerated() {
+        paths += valuePaths(in: child, prefix: prefix + [.index(index)])
+      }
+    }
+    return paths
+  }
+
+  private static func replacing(_ path: [PathComponent], in value: Any, with substitute: Any) -> Any {
+    guard let head = path.first else { return substitute }
+    let rest = Array(path.dropFirst())
+
+    switch head {
+    case .key(let key):
+      guard var dictionary = value as? [String: Any], let child = dictionary[key] else { return value }
+      dictionary[key] = replacing(rest, in: child, with: substitute)
+      return dictionary
+    case .index(let index):
+      guard var array = value as? [Any], array.indices.contains(index) else { return value }
+      array[index] = replacing(rest, in: array[index], with: substitute)
+      return array
+    }
+  }
+}
+
+// MARK: - SeededGenerator
+
+/// SplitMix64. Indices are taken from `next()` directly rather than through
+/// `Int.random(in:using:)` or `randomElement(using:)`, whose use of the
+/// generator the standard library doesn't promise to keep stable.
+private struct SeededGenerator: RandomNumberGenerator {
+  private var state: UInt64
+
+  init(seed: UInt64) {
+    state = seed
+  }
+
+  mutating func next() -> UInt64 {
+    state &+= 0x9E37_79B9_7F4A_7C15
+    var z = state
+    z = (z ^ (z >> 30)) &* 0xBF58_476D_1CE4_E5B9
+    z = (z ^ (z >> 27)) &* 0x94D0_49BB_1331_11EB
+    return z ^ (z >> 31)
+  }
+
+  /// A value in `0..<upperBound`. Modulo bias is irrelevant here.
+  mutating func index(below upperBound: Int) -> Int {
+    Int(next() % UInt64(upperBound))
+  }
+}
@@ -219,4 +219,46 @@ struct SessionWatcherTimerPolicyTests {
 
     #expect(interval == SessionWatcherTimerPolicy.staleWatcherRecoveryInterval)
   }
+
+  @Test("Stale recovery runs when the file grew without events")
+  func staleRecoveryRunsWhenFileGrewWithoutEvents() {
+    let now = Date(timeIntervalSince1970: 10_000)
+
+    #expect(SessionWatcherTimerPolicy.shouldRecoverStaleWatcher(
+      lastFileEventTime: now.addingTimeInterval(-6),
+      currentFileSize: 200,
+      lastKnownFileSize: 100,
+      unterminatedTailSize: 0,
+      now: now
+    ))
+    #expect(!SessionWatcherTimerPolicy.shouldRecoverStaleWatcher(
+      lastFileEventTime: now.addingTimeInterval(-2),
+      currentFileSize: 200,
+      lastKnownFileSize: 100,
+      unterminatedTailSize: 0,
+      now: now
+    ))
+  }
+
+  @Test("Stale recovery waits for an unterminated tail to grow")
+  func staleRecoveryWaitsForUnterminatedTailToGrow() {
+    let now = Date(timeIntervalSince1970: 10_000)
+
+    // The CLI was killed mid-write: the last recovery saw 150 bytes, of which
+    // only the first 100 end in a newline.
+    #expect(!SessionWatcherTimerPolicy.shouldRecoverStaleWatcher(
+      lastFileEventTime: now.addingTimeInterval(-60),
+      currentFileSize: 150,
+      lastKnownFileSize: 100,
+      unterminatedTailSize: 150,
+      now: now
+    ))
+    #expect(SessionWatcherTimerPolicy.shouldRecoverStaleWatcher(
+      lastFileEventTime: now.addingTimeInterval(-60),
+      currentFileSize: 151,
+      lastKnownFileSize: 100,
+      unterminatedTailSize: 150,
+      now: now
+    ))
+  }
 }
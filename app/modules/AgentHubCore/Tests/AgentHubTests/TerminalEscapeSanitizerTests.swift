@@ -0,0 +1,84 @@
+import Foundation
+import Testing
+
+@testable import AgentHubCore
+
+@Suite("TerminalEscapeSanitizer")
+struct TerminalEscapeSanitizerTests {
+
+  @Test("Strips SGR color codes from dev-server banners")
+  func stripsColorCodes() {
+    let banner = "  \u{1B}[32m➜\u{1B}[39m  \u{1B}[1mLocal\u{1B}[22m:   \u{1B}[36mhttp://localhost:\u{1B}[1m5173\u{1B}[22m/\u{1B}[39m"
+
+    #expect(TerminalEscapeSanitizer.strip(banner) == "  ➜  Local:   http://localhost:5173/")
+  }
+
+  @Test("Strips OSC hyperlinks but keeps their label")
+  func stripsOSCHyperlinks() {
+    let text = "\u{1B}]8;;https://example.com\u{07}docs\u{1B}]8;;\u{07} ready"
+
+    #expect(TerminalEscapeSanitizer.strip(text) == "docs ready")
+  }
+
+  @Test("Strips charset designators left by tput sgr0")
+  func stripsCharsetDesignators() {
+    let text = "\u{1B}[1m\u{1B}(Bmake\u{1B}[m\u{1B}(B: Nothing to be done \u{1B})0x"
+
+    #expect(TerminalEscapeSanitizer.strip(text) == "make: Nothing to be done x")
+  }
+
+  @Test("Strips cursor save/restore and keypad mode escapes")
+  func stripsPrivateTwoCharacterEscapes() {
+    let text = "\u{1B}7\u{1B}[1;1Hstatus\u{1B}8\u{1B}=\u{1B}> ok"
+
+    #expect(TerminalEscapeSanitizer.strip(text) == "status ok")
+  }
+
+  @Test("Strips DCS, SOS, PM and APC strings with their payload")
+  func stripsStringTerminatedSequences() {
+    let text = "a\u{1B}Pq#0;2;0;0;0#0~~\u{1B}\\b\u{1B}Xsos\u{07}c\u{1B}^pm\u{1B}\\d\u{1B}_apc\u{1B}\\e"
+
+    #expect(TerminalEscapeSanitizer.strip(text) == "abcde")
+  }
+
+  @Test("Unterminated OSC or DCS stops at the end of its line")
+  func unterminatedStringSequenceStopsAtEndOfLine() {
+    #expect(TerminalEscapeSanitizer.strip("a\u{1B}]0;title\nb") == "a\nb")
+    #expect(TerminalEscapeSanitizer.strip("a\u{1B}Pq#0;2\nb") == "a\nb")
+  }
+
+  @Test("Keeps only the last frame of carriage-return progress output")
+  func keepsLastProgressFrame() {
+    #expect(TerminalEscapeSanitizer.strip("10%\r20%\r30%") == "30%")
+    #expect(TerminalEscapeSanitizer.strip("Downloading 50%\r\nDone\r\n") == "Downloading 50%\nDone\n")
+    #expect(TerminalEscapeSanitizer.strip("build\n[=>  ]\r[==> ]\r\ntest") == "build\n[==> ]\ntest")
+  }
+
+  @Test("Drops a sequence cut off by preview truncation")
+  func dropsTruncatedSequence() {
+    #expect(TerminalEscapeSanitizer.strip("Building \u{1B}[3") == "Building ")
+  }
+
+  @Test("Removes control characters but keeps tabs and newlines")
+  func removesControlCharacters() {
+    #expect(TerminalEscapeSanitizer.strip("a\u{07}b\u{08}c\td\ne\r") == "abc\td\ne")
+  }
+
+  @Test("Returns plain text unchanged")
+  func returnsPlainTextUnchanged() {
+    let text = "Server is running at http://localhost:3000 — ready ✅"
+
+    #expect(TerminalEscapeSanitizer.strip(text) == text)
+  }
+
+  @Test("Activity descriptions are sanitized")
+  func activityDescriptionsAreSanitized() {
+    let line = """
+      {"type":"assistant","timestamp":"2026-01-01T00:00:00Z","message":{"role":"assistant","content":[{"type":"tool_use","id":"tu1","name":"Bash","input":{"command":"echo \\u001b[31mred\\u001b[0m"}}]}}
+      """
+    var result = SessionJSONLParser.ParseResult()
+    SessionJSONLParser.parseNewLines([line], into: &result)
+
+    #expect(result.recentActivities.last?.description == "echo red")
+  }
+}
@@ -0,0 +1,156 @@
+import Foundation
+import Testing
+
+@testable import AgentHubCore
+
+@Suite("UTF8ChunkDecoder")
+struct UTF8ChunkDecoderTests {
+
+  @Test("Complete lines are consumed whole")
+  func completeLinesAreConsumedWhole() {
+    let data = Data("line ➜ one\nline two\n".utf8)
+
+    let chunk = UTF8ChunkDecoder.decodeChunk(data)
+
+    #expect(chunk.lines == ["line ➜ one", "line two"])
+    #expect(chunk.consumedByteCount == data.count)
+    #expect(!chunk.containedInvalidUTF8)
+  }
+
+  @Test("Invalid bytes are replaced instead of dropping the chunk")
+  func invalidBytesAreReplaced() {
+    var data = Data("first\n".utf8)
+    data.append(contentsOf: [0x63, 0x61, 0x66, 0xE9, 0x0A]) // "caf\u{E9}\n" in Latin-1
+    data.append(Data("third\n".utf8))
+
+    let chunk = UTF8ChunkDecoder.decodeChunk(data)
+
+    #expect(chunk.lines == ["first", "caf\u{FFFD}", "third"])
+    #expect(chunk.consumedByteCount == data.count)
+    #expect(chunk.containedInvalidUTF8)
+  }
+
+  @Test("Unfinished line is held back for the next read")
+  func unfinishedLineIsHeldBack() {
+    let full = Data("done\n{\"text\":\"ready ➜\"}\n".utf8)
+    // Cut inside the 3-byte "➜", so the second line is both unfinished and
+    // ends mid-character.
+    let cut = full.count - 5
+    let firstRead = full.prefix(cut)
+
+    let first = UTF8ChunkDecoder.decodeChunk(firstRead)
+
+    #expect(first.lines == ["done"])
+    #expect(first.consumedByteCount == 5)
+    #expect(!first.containedInvalidUTF8)
+
+    let second = UTF8ChunkDecoder.decodeChunk(full.suffix(from: first.consumedByteCount))
+
+    #expect(second.lines == ["{\"text\":\"ready ➜\"}"])
+    #expect(second.consumedByteCount == full.count - first.consumedByteCount)
+    #expect(!second.containedInvalidUTF8)
+  }
+
+  @Test("Chunk without a newline consumes nothing")
+  func chunkWithoutNewlineConsumesNothing() {
+    let chunk = UTF8ChunkDecoder.decodeChunk(Data("{\"type\":\"assis".utf8))
+
+    #expect(chunk.lines.isEmpty)
+    #expect(chunk.consumedByteCount == 0)
+  }
+
+  @Test("Watcher parses a line written in two parts exactly once")
+  func watcherParsesSplitLineOnce() throws {
+    let url = FileManager.default.temporaryDirectory
+      .appendingPathComponent("utf8_chunk_\(UUID().uuidString).jsonl")
+    defer { try? FileManager.default.removeItem(at: url) }
+
+    let line = Data("""
+      {"type":"user","timestamp":"2026-01-01T00:00:01Z","message":{"role":"user","content":[{"type":"text","text":"Start ➜ now"}]}}
+
+      """.utf8)
+    let cut = line.range(of: Data("➜".utf8))!.lowerBound + 1
+    try line.prefix(cut).write(to: url)
+
+    let watcher = SessionFileWatcher(approvalNotificationService: NoOpApprovalNotificationService())
+    var position: UInt64 = 0
+    var result = SessionJSONLParser.ParseResult()
+
+    let firstLines = watcher.readNewLines(from: url.path, startingAt: &position)
+    SessionJSONLParser.parseNewLines(firstLines, into: &result)
+    #expect(firstLines.isEmpty)
+    #expect(position == 0)
+
+    let handle = try FileHandle(forWritingTo: url)
+    try handle.seekToEnd()
+    try handle.write(contentsOf: line.suffix(from: cut))
+    try handle.close()
+
+    let secondLines = watcher.readNewLines(from: url.path, startingAt: &position)
+    SessionJSONLParser.parseNewLines(secondLines, into: &result)
+    let thirdLines = watcher.readNewLines(from: url.path, startingAt: &position)
+    SessionJSONLParser.parseNewLines(thirdLines, into: &result)
+
+    #expect(secondLines.count == 1)
+    #expect(thirdLines.isEmpty)
+    #expect(position == UInt64(line.count))
+    #expect(result.messageCount == 1)
+    #expect(result.recentActivities.last?.description == "Start ➜ now")
+  }
+
+  @Test("Monitoring that starts mid-write picks up the unfinished line")
+  func monitoringStartedMidWriteKeepsUnfinishedLine() async throws {
+    let url = FileManager.default.temporaryDirectory
+      .appendingPathComponent("utf8_chunk_\(UUID().uuidString).jsonl")
+    defer { try? FileManager.default.removeItem(at: url) }
+
+    let first = Data("""
+      {"type":"user","timestamp":"2026-01-01T00:00:00Z","message":{"role":"user","content":[{"type":"text","text":"One"}]}}
+
+      """.utf8)
+    let second = Data("""
+      {"type":"user","timestamp":"2026-01-01T00:00:01Z","message":{"role":"user","content":[{"type":"text","text":"Two"}]}}
+
+      """.utf8)
+    let cut = second.count / 2
+    try (first + second.prefix(cut)).write(to: url)
+
+    let watcher = SessionFileWatcher(approvalNotificationService: NoOpApprovalNotificationService())
+    await watcher.startMonitoring(sessionId: "mid-write", projectPath: "/tmp", sessionFilePath: url.path)
+    #expect(await watcher.getState(sessionId: "mid-write")?.messageCount == 1)
+
+    let handle = try FileHandle(forWritingTo: url)
+    try handle.seekToEnd()
+    try handle.write(contentsOf: second.suffix(from: cut))
+    try handle.close()
+
+    await watcher.refreshState(sessionId: "mid-write")
+    let state = await watcher.getState(sessionId: "mid-write")
+    #expect(state?.messageCount == 2)
+    #expect(state?.recentActivities.last?.description == "Two")
+    await watcher.stopMonitoring(sessionId: "mid-write")
+  }
+
+  @Test("Whole-file parse keeps lines around an invalid byte")
+  func wholeFileParseKeepsLinesAroundInvalidByte() throws {
+    let url = FileManager.default.temporaryDirectory
+      .appendingPathComponent("utf8_chunk_\(UUID().uuidString).jsonl")
+    defer { try? FileManager.default.removeItem(at: url) }
+
+    var data = Data("""
+      {"type":"assistant","timestamp":"2026-01-01T00:00:00Z","message":{"role":"assistant","model":"claude-opus-4-1","content":[{"type":"text","text":"Hi"}]}}
+
+      """.utf8)
+    data.append(contentsOf: [0xFF, 0xFE, 0x0A])
+    data.append(Data("""
+      {"type":"user","timestamp":"2026-01-01T00:00:01Z","message":{"role":"user","content":[{"type":"text","text":"Thanks"}]}}
+
+      """.utf8))
+    try data.write(to: url)
+
+    let result = SessionJSONLParser.parseSessionFile(at: url.path)
+
+    #expect(result.model == "claude-opus-4-1")
+    #expect(result.messageCount == 2)
+  }
+}